@@ -1,6 +1,6 @@
 use bevy::prelude::*;
 
-use crate::util::movement_axis;
+use crate::util::{integrate_velocity, movement_axis, speed_multiplier};
 
 /// A set of options for initializing a FlyCamera.
 /// Attach this component to a [`Camera2dBundle`](https://docs.rs/bevy/0.4.0/bevy/prelude/struct.Camera2dBundle.html) bundle to control it with your keyboard.
@@ -17,8 +17,10 @@ pub struct FlyCamera2d {
 	pub accel: f32,
 	/// The maximum speed the FlyCamera can move at.
 	pub max_speed: f32,
-	/// The amount of deceleration to apply to the camera's motion.
-	pub friction: f32,
+	/// The half-life, in seconds, for velocity to decay to half its value.
+	pub damping_half_life: f32,
+	/// Additional quadratic drag coefficient, for bleeding off high speeds faster.
+	pub drag_coeff: f32,
 	/// The current velocity of the FlyCamera2d. This value is always up-to-date, enforced by [FlyCameraPlugin](struct.FlyCameraPlugin.html)
 	pub velocity: Vec2,
 	/// Key used to move left. Defaults to <kbd>A</kbd>
@@ -31,6 +33,14 @@ pub struct FlyCamera2d {
 	pub key_down: KeyCode,
 	/// If `false`, disable keyboard control of the camera. Defaults to `true`
 	pub enabled: bool,
+	/// Key held to sprint, multiplying `accel` and `max_speed` by `sprint_factor`. Defaults to <kbd>LControl</kbd>
+	pub key_sprint: KeyCode,
+	/// Key held to move slowly, multiplying `accel` and `max_speed` by `slow_factor`. Defaults to <kbd>LAlt</kbd>
+	pub key_slow: KeyCode,
+	/// The speed multiplier applied while `key_sprint` is held. Defaults to `3.0`
+	pub sprint_factor: f32,
+	/// The speed multiplier applied while `key_slow` is held. Defaults to `0.25`
+	pub slow_factor: f32,
 }
 
 impl Default for FlyCamera2d {
@@ -39,13 +49,18 @@ impl Default for FlyCamera2d {
 		Self {
 			accel: 3.0 * MUL_2D,
 			max_speed: 1.0 * MUL_2D,
-			friction: 1.75 * MUL_2D,
+			damping_half_life: 0.15,
+			drag_coeff: 0.0,
 			velocity: Vec2::ZERO,
 			key_left: KeyCode::KeyA,
 			key_right: KeyCode::KeyD,
 			key_up: KeyCode::KeyW,
 			key_down: KeyCode::KeyS,
 			enabled: true,
+			key_sprint: KeyCode::ControlLeft,
+			key_slow: KeyCode::AltLeft,
+			sprint_factor: 3.0,
+			slow_factor: 0.25,
 		}
 	}
 }
@@ -65,35 +80,32 @@ pub fn camera_2d_movement_system(
 			(0.0, 0.0)
 		};
 
+		let multiplier = speed_multiplier(
+			&keyboard_input,
+			options.key_sprint,
+			options.key_slow,
+			options.sprint_factor,
+			options.slow_factor,
+		);
+
 		let accel: Vec2 = (Vec2::X * axis_h) + (Vec2::Y * axis_v);
 		let accel: Vec2 = if accel.length() != 0.0 {
-			accel.normalize() * options.accel
-		} else {
-			Vec2::ZERO
-		};
-
-		let friction: Vec2 = if options.velocity.length() != 0.0 {
-			options.velocity.normalize() * -1.0 * options.friction
+			accel.normalize() * options.accel * multiplier
 		} else {
 			Vec2::ZERO
 		};
 
-		options.velocity += accel * time.delta_secs();
+		let dt = time.delta_secs();
+		let max_speed = options.max_speed * multiplier;
 
-		// clamp within max speed
-		if options.velocity.length() > options.max_speed {
-			options.velocity = options.velocity.normalize() * options.max_speed;
-		}
-
-		let delta_friction = friction * time.delta_secs();
-
-		options.velocity = if (options.velocity + delta_friction).signum()
-			!= options.velocity.signum()
-		{
-			Vec2::ZERO
-		} else {
-			options.velocity + delta_friction
-		};
+		options.velocity = integrate_velocity(
+			options.velocity,
+			accel,
+			max_speed,
+			options.drag_coeff,
+			options.damping_half_life,
+			dt,
+		);
 
 		transform.translation +=
 			Vec3::new(options.velocity.x, options.velocity.y, 0.0);
@@ -8,6 +8,9 @@
 //! - <kbd>W</kbd> / <kbd>A</kbd> / <kbd>S</kbd> / <kbd>D</kbd> - Move along the horizontal plane
 //! - Shift - Move downward
 //! - Space - Move upward
+//! - LControl / LAlt - Hold to sprint / move slowly
+//! - Tab - Cycle which setting (max speed / sensitivity / accel) the scroll wheel tunes
+//! - <kbd>`</kbd> (backtick) - Cycle control to the next camera in the scene
 //!
 //! ## Example
 //! ```no_compile
@@ -36,6 +39,7 @@
 //!
 //! The default keybinds are:
 //! - <kbd>W</kbd> / <kbd>A</kbd> / <kbd>S</kbd> / <kbd>D</kbd> - Move along the 2d plane
+//! - LControl / LAlt - Hold to sprint / move slowly
 //!
 //! ## Example
 //! ```no_compile
@@ -50,9 +54,13 @@
 //!
 //! There's also a basic piece of example code included in `/examples/2d.rs`
 
-use bevy::{input::mouse::MouseMotion, prelude::*};
+use bevy::{
+	input::mouse::{MouseMotion, MouseWheel},
+	prelude::*,
+};
 use cam2d::camera_2d_movement_system;
-use util::movement_axis;
+use std::ops::DerefMut;
+use util::{integrate_velocity, movement_axis, speed_multiplier};
 
 mod cam2d;
 mod util;
@@ -76,8 +84,10 @@ pub struct FlyCamera {
 	pub max_speed: f32,
 	/// The sensitivity of the FlyCamera's motion based on mouse movement. Defaults to `3.0`
 	pub sensitivity: f32,
-	/// The amount of deceleration to apply to the camera's motion. Defaults to `1.0`
-	pub friction: f32,
+	/// The half-life, in seconds, for velocity to decay to half its value. Defaults to `0.15`
+	pub damping_half_life: f32,
+	/// Additional quadratic drag coefficient, for bleeding off high speeds faster. Defaults to `0.0`
+	pub drag_coeff: f32,
 	/// The current pitch of the FlyCamera in degrees. This value is always up-to-date, enforced by [FlyCameraPlugin](struct.FlyCameraPlugin.html)
 	pub pitch: f32,
 	/// The current pitch of the FlyCamera in degrees. This value is always up-to-date, enforced by [FlyCameraPlugin](struct.FlyCameraPlugin.html)
@@ -98,6 +108,50 @@ pub struct FlyCamera {
 	pub key_down: KeyCode,
 	/// If `false`, disable keyboard control of the camera. Defaults to `true`
 	pub enabled: bool,
+	/// Which setting the scroll wheel currently adjusts, cycled with `scroll_cycle_key`. Defaults to `ScrollAdjust::MaxSpeed`
+	pub scroll_target: ScrollAdjust,
+	/// Key used to cycle which setting the scroll wheel adjusts. Defaults to <kbd>Tab</kbd>
+	pub scroll_cycle_key: KeyCode,
+	/// If `false`, disable scroll-wheel tuning of `scroll_target`. Defaults to `true`
+	pub scroll_enabled: bool,
+	/// Key held to sprint, multiplying `accel` and `max_speed` by `sprint_factor`. Defaults to <kbd>LControl</kbd>
+	pub key_sprint: KeyCode,
+	/// Key held to move slowly, multiplying `accel` and `max_speed` by `slow_factor`. Defaults to <kbd>LAlt</kbd>
+	pub key_slow: KeyCode,
+	/// The speed multiplier applied while `key_sprint` is held. Defaults to `3.0`
+	pub sprint_factor: f32,
+	/// The speed multiplier applied while `key_slow` is held. Defaults to `0.25`
+	pub slow_factor: f32,
+	/// If `true`, allow the arrow keys (or configured keys) to pan/tilt the camera as an alternative to mouse-look. Defaults to `false`
+	pub look_with_keys: bool,
+	/// Key used to look left. Defaults to <kbd>Left</kbd>
+	pub key_look_left: KeyCode,
+	/// Key used to look right. Defaults to <kbd>Right</kbd>
+	pub key_look_right: KeyCode,
+	/// Key used to look up. Defaults to <kbd>Up</kbd>
+	pub key_look_up: KeyCode,
+	/// Key used to look down. Defaults to <kbd>Down</kbd>
+	pub key_look_down: KeyCode,
+	/// The speed of keyboard look, independent of `sensitivity`. Defaults to `60.0`
+	pub key_look_speed: f32,
+	/// Key used to roll counter-clockwise. Defaults to <kbd>Q</kbd>
+	pub key_roll_left: KeyCode,
+	/// Key used to roll clockwise. Defaults to <kbd>E</kbd>
+	pub key_roll_right: KeyCode,
+	/// The current roll of the FlyCamera in degrees. This value is always up-to-date, enforced by [FlyCameraPlugin](struct.FlyCameraPlugin.html)
+	pub roll: f32,
+	/// The current control mode of the FlyCamera. Defaults to `CameraMode::Free`
+	pub mode: CameraMode,
+	/// The entity to orbit or follow when `mode` is `CameraMode::Orbit` or `CameraMode::Follow`. Defaults to `None`
+	pub target: Option<Entity>,
+	/// The distance maintained from `target` while orbiting. Defaults to `5.0`
+	pub orbit_distance: f32,
+	/// The offset maintained from `target` while following. Defaults to `Vec3::new(0.0, 2.0, -5.0)`
+	pub follow_offset: Vec3,
+	/// The half-life, in seconds, used to smoothly lerp the camera toward `target` while following. Defaults to `0.15`
+	pub follow_lerp: f32,
+	/// Key used to cycle through `CameraMode`s. Defaults to <kbd>C</kbd>
+	pub key_cycle_mode: KeyCode,
 }
 impl Default for FlyCamera {
 	fn default() -> Self {
@@ -105,7 +159,8 @@ impl Default for FlyCamera {
 			accel: 1.5,
 			max_speed: 0.5,
 			sensitivity: 3.0,
-			friction: 1.0,
+			damping_half_life: 0.15,
+			drag_coeff: 0.0,
 			pitch: 0.0,
 			yaw: 0.0,
 			velocity: Vec3::ZERO,
@@ -116,6 +171,67 @@ impl Default for FlyCamera {
 			key_up: KeyCode::Space,
 			key_down: KeyCode::LShift,
 			enabled: true,
+			scroll_target: ScrollAdjust::MaxSpeed,
+			scroll_cycle_key: KeyCode::Tab,
+			scroll_enabled: true,
+			key_sprint: KeyCode::LControl,
+			key_slow: KeyCode::LAlt,
+			sprint_factor: 3.0,
+			slow_factor: 0.25,
+			look_with_keys: false,
+			key_look_left: KeyCode::Left,
+			key_look_right: KeyCode::Right,
+			key_look_up: KeyCode::Up,
+			key_look_down: KeyCode::Down,
+			key_look_speed: 60.0,
+			key_roll_left: KeyCode::Q,
+			key_roll_right: KeyCode::E,
+			roll: 0.0,
+			mode: CameraMode::Free,
+			target: None,
+			orbit_distance: 5.0,
+			follow_offset: Vec3::new(0.0, 2.0, -5.0),
+			follow_lerp: 0.15,
+			key_cycle_mode: KeyCode::C,
+		}
+	}
+}
+
+/// The `FlyCamera` setting currently targeted by scroll-wheel tuning. See [`FlyCamera::scroll_target`](struct.FlyCamera.html#structfield.scroll_target).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScrollAdjust {
+	MaxSpeed,
+	Sensitivity,
+	Accel,
+}
+
+impl ScrollAdjust {
+	fn next(self) -> Self {
+		match self {
+			ScrollAdjust::MaxSpeed => ScrollAdjust::Sensitivity,
+			ScrollAdjust::Sensitivity => ScrollAdjust::Accel,
+			ScrollAdjust::Accel => ScrollAdjust::MaxSpeed,
+		}
+	}
+}
+
+/// The control mode a `FlyCamera` is currently in, cycled with [`FlyCamera::key_cycle_mode`](struct.FlyCamera.html#structfield.key_cycle_mode).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CameraMode {
+	/// Free-fly, controlled directly by movement/look input. The default.
+	Free,
+	/// Orbits `FlyCamera::target` at `FlyCamera::orbit_distance`, always facing it.
+	Orbit,
+	/// Tracks `FlyCamera::target` at `FlyCamera::follow_offset`, smoothed by `FlyCamera::follow_lerp`.
+	Follow,
+}
+
+impl CameraMode {
+	fn next(self) -> Self {
+		match self {
+			CameraMode::Free => CameraMode::Orbit,
+			CameraMode::Orbit => CameraMode::Follow,
+			CameraMode::Follow => CameraMode::Free,
 		}
 	}
 }
@@ -124,6 +240,8 @@ impl Default for FlyCamera {
 pub mod camera_events {
     use bevy::{math::Vec2};
 
+    use super::ScrollAdjust;
+
 	#[derive(Debug)]
 	pub enum EventType {
 		// Move forward or back, bool is whether to move horizontally
@@ -132,6 +250,12 @@ pub mod camera_events {
 		Strafe(f32),
 		MoveVertical(f32),
 		LookMouse(Vec2),
+		// Keyboard-driven look/roll delta, pre-scaled by `key_look_speed`. Applied without the
+		// mouse `sensitivity` multiply, so setting `sensitivity: 0.0` to disable mouse-look
+		// can't also zero out or corrupt keyboard-look.
+		LookKeys(Vec2, f32),
+		// Which setting to adjust, and the raw scroll delta to apply to it
+		AdjustSetting(ScrollAdjust, f32),
 	}
 
 	#[derive(Debug)]
@@ -185,27 +309,73 @@ fn consume_camera_rotation_events(
 		if !options.enabled {
 			continue;
 		}
-		let mut delta = Vec2::ZERO;
+		let mut mouse_delta = Vec2::ZERO;
+		let mut key_delta = Vec2::ZERO;
+		let mut key_roll = 0.0;
 		for event in events.iter() {
 			match event.event_type {
-			    camera_events::EventType::LookMouse(mouse_delta) => { delta += mouse_delta }
+			    camera_events::EventType::LookMouse(delta) => { mouse_delta += delta }
+			    camera_events::EventType::LookKeys(delta, roll) => {
+					key_delta += delta;
+					key_roll += roll;
+				}
 				_ => {}
 			}
 		}
 
-		if delta != Vec2::ZERO {
-		
-			options.yaw -= delta.x * options.sensitivity * time.delta_seconds();
-			options.pitch += delta.y * options.sensitivity * time.delta_seconds();
+		let roll_changed = key_roll != 0.0;
+		if roll_changed {
+			options.roll += key_roll * time.delta_seconds();
+		}
+
+		if mouse_delta != Vec2::ZERO || key_delta != Vec2::ZERO {
+			options.yaw -= (mouse_delta.x * options.sensitivity + key_delta.x) * time.delta_seconds();
+			options.pitch += (mouse_delta.y * options.sensitivity + key_delta.y) * time.delta_seconds();
 
 			options.pitch = options.pitch.clamp(-89.0, 89.9);
 			// println!("pitch: {}, yaw: {}", options.pitch, options.yaw);
+		}
 
+		if mouse_delta != Vec2::ZERO || key_delta != Vec2::ZERO || roll_changed {
 			let yaw_radians = options.yaw.to_radians();
 			let pitch_radians = options.pitch.to_radians();
+			let roll_radians = options.roll.to_radians();
 
 			transform.rotation = Quat::from_axis_angle(Vec3::Y, yaw_radians)
-				* Quat::from_axis_angle(-Vec3::X, pitch_radians);
+				* Quat::from_axis_angle(-Vec3::X, pitch_radians)
+				* Quat::from_axis_angle(Vec3::Z, roll_radians);
+		}
+	}
+}
+
+fn emit_camera_key_look_events(
+	keyboard_input: Res<Input<KeyCode>>,
+	mut emit_events: bevy::prelude::EventWriter<camera_events::CameraEvent>,
+	query: Query<&FlyCamera>,
+) {
+	for options in query.iter() {
+		if !options.enabled {
+			continue;
+		}
+
+		// Roll isn't gated on `look_with_keys` - it's a separate feature, always live while enabled.
+		let roll_axis = movement_axis(&keyboard_input, options.key_roll_right, options.key_roll_left);
+
+		let (axis_h, axis_v) = if options.look_with_keys {
+			(
+				movement_axis(&keyboard_input, options.key_look_right, options.key_look_left),
+				movement_axis(&keyboard_input, options.key_look_down, options.key_look_up),
+			)
+		} else {
+			(0.0, 0.0)
+		};
+
+		if axis_h != 0.0 || axis_v != 0.0 || roll_axis != 0.0 {
+			let delta = Vec2::new(axis_h, axis_v) * options.key_look_speed;
+			let roll = roll_axis * options.key_look_speed;
+			emit_events.send(camera_events::CameraEvent {
+				event_type: camera_events::EventType::LookKeys(delta, roll),
+			})
 		}
 	}
 }
@@ -250,19 +420,93 @@ fn emit_camera_movement_events(
 	}
 }
 
+fn emit_camera_scroll_events(
+	keyboard_input: Res<Input<KeyCode>>,
+	mut mouse_wheel_event_reader: EventReader<MouseWheel>,
+	mut emit_events: bevy::prelude::EventWriter<camera_events::CameraEvent>,
+	mut query: Query<&mut FlyCamera>,
+) {
+	let mut scroll_delta = 0.0;
+	for event in mouse_wheel_event_reader.iter() {
+		scroll_delta += event.y;
+	}
+
+	for mut options in query.iter_mut() {
+		if !options.enabled || !options.scroll_enabled {
+			continue;
+		}
+
+		if keyboard_input.just_pressed(options.scroll_cycle_key) {
+			options.scroll_target = options.scroll_target.next();
+		}
+
+		if scroll_delta != 0.0 {
+			emit_events.send(camera_events::CameraEvent {
+				event_type: camera_events::EventType::AdjustSetting(
+					options.scroll_target,
+					scroll_delta,
+				),
+			})
+		}
+	}
+}
+
+fn consume_camera_scroll_events(
+	mut events: EventReader<camera_events::CameraEvent>,
+	mut query: Query<&mut FlyCamera>,
+) {
+	let mut adjustments: Vec<(ScrollAdjust, f32)> = Vec::new();
+	for event in events.iter() {
+		if let camera_events::EventType::AdjustSetting(target, delta) = event.event_type {
+			adjustments.push((target, delta));
+		}
+	}
+
+	if adjustments.is_empty() {
+		return;
+	}
+
+	for mut options in query.iter_mut() {
+		if !options.enabled || !options.scroll_enabled {
+			continue;
+		}
+
+		for (target, delta) in &adjustments {
+			match target {
+				ScrollAdjust::MaxSpeed => {
+					options.max_speed = (options.max_speed + delta * 0.05).max(0.01);
+				}
+				ScrollAdjust::Sensitivity => {
+					options.sensitivity = (options.sensitivity + delta * 0.1).max(0.1);
+				}
+				ScrollAdjust::Accel => {
+					options.accel = (options.accel + delta * 0.1).max(0.01);
+				}
+			}
+		}
+	}
+}
+
 fn consume_camera_events(
 	time: Res<Time>,
+	keyboard_input: Res<Input<KeyCode>>,
 	mut events: EventReader<camera_events::CameraEvent>,
 	mut query: Query<(&mut FlyCamera, &mut Transform)>,
 ) {
 	for (mut options, mut transform) in query.iter_mut() {
+		if !options.enabled {
+			continue;
+		}
+
 		let mut accel = Vec3::ZERO;
+		let mut forward_distance = 0.0;
 		let rotation = transform.rotation;
 
 		for event in events.iter() {
 			match event.event_type {
 				camera_events::EventType::Move(distance, _horizontal) => {
 					accel += forward_walk_vector(&rotation) * distance;
+					forward_distance += distance;
 				}
 			    camera_events::EventType::Strafe(distance) => {
 					accel += strafe_vector(&rotation) * distance;
@@ -271,40 +515,191 @@ fn consume_camera_events(
 					accel += Vec3::Y * distance
 				}
 			    camera_events::EventType::LookMouse(_) => {}
+			    camera_events::EventType::LookKeys(_, _) => {}
+			    camera_events::EventType::AdjustSetting(_, _) => {}
 			}
 		}
 
-		let accel: Vec3 = if accel.length() != 0.0 {
-			accel.normalize() * options.accel
-		} else {
-			Vec3::ZERO
-		};
+		if options.mode == CameraMode::Orbit {
+			// the forward/back movement axis zooms the orbit instead of moving the camera directly
+			let zoom = forward_distance * options.accel * time.delta_seconds();
+			options.orbit_distance = (options.orbit_distance - zoom).max(0.1);
+		}
+
+		// Placement in Orbit/Follow mode is driven entirely by camera_mode_system, but velocity
+		// still needs to keep decaying below so re-entering Free mode doesn't jump from a stale value.
+		let driving = options.mode == CameraMode::Free;
 
-		let friction: Vec3 = if options.velocity.length() != 0.0 {
-			options.velocity.normalize() * -1.0 * options.friction
+		let multiplier = speed_multiplier(
+			&keyboard_input,
+			options.key_sprint,
+			options.key_slow,
+			options.sprint_factor,
+			options.slow_factor,
+		);
+
+		let accel: Vec3 = if driving && accel.length() != 0.0 {
+			accel.normalize() * options.accel * multiplier
 		} else {
 			Vec3::ZERO
 		};
 
-		options.velocity += accel * time.delta_seconds();
+		let dt = time.delta_seconds();
+		let max_speed = options.max_speed * multiplier;
+
+		options.velocity = integrate_velocity(
+			options.velocity,
+			accel,
+			max_speed,
+			options.drag_coeff,
+			options.damping_half_life,
+			dt,
+		);
 
-		// clamp within max speed
-		if options.velocity.length() > options.max_speed {
-			options.velocity = options.velocity.normalize() * options.max_speed;
+		if driving {
+			transform.translation += options.velocity;
 		}
+	}
+}
 
-		let delta_friction = friction * time.delta_seconds();
+fn camera_mode_system(
+	time: Res<Time>,
+	keyboard_input: Res<Input<KeyCode>>,
+	mut query: Query<(&mut FlyCamera, &mut Transform)>,
+	target_query: Query<&Transform, Without<FlyCamera>>,
+) {
+	for (mut options, mut transform) in query.iter_mut() {
+		if !options.enabled {
+			continue;
+		}
 
-		options.velocity = if (options.velocity + delta_friction).signum()
-			!= options.velocity.signum()
-		{
-			Vec3::ZERO
-		} else {
-			options.velocity + delta_friction
+		if keyboard_input.just_pressed(options.key_cycle_mode) {
+			options.mode = options.mode.next();
+		}
+
+		let target = match options.target {
+			Some(target) => target,
+			None => continue,
+		};
+
+		let target_transform = match target_query.get(target) {
+			Ok(target_transform) => target_transform,
+			Err(_) => continue,
 		};
 
-		transform.translation += options.velocity;
+		match options.mode {
+			CameraMode::Free => {}
+			CameraMode::Orbit => {
+				// yaw/pitch are already kept up to date by consume_camera_rotation_events
+				let direction = forward_vector(&transform.rotation);
+				transform.translation =
+					target_transform.translation + direction * options.orbit_distance;
+			}
+			CameraMode::Follow => {
+				let desired = target_transform.translation + options.follow_offset;
+				let t = 1.0 - 0.5_f32.powf(time.delta_seconds() / options.follow_lerp.max(0.0001));
+				transform.translation = transform.translation.lerp(desired, t);
+			}
+		}
+	}
+}
+
+/// Marks the camera entity currently under user control. [`FlyCameraPlugin`](struct.FlyCameraPlugin.html) drives
+/// input and scene switching based on this marker, so only one camera reacts to input/look events at a time even
+/// when a scene has several (e.g. cameras imported from a glTF file).
+pub struct FlyCameraActive;
+
+/// Configures the key used to cycle control between all cameras in the scene.
+/// Insert this resource yourself to override the default key.
+pub struct CameraCycleConfig {
+	/// Key used to hand control to the next camera in the scene. Defaults to <kbd>`</kbd> (Grave)
+	pub key_next_camera: KeyCode,
+}
+
+impl Default for CameraCycleConfig {
+	fn default() -> Self {
+		Self {
+			key_next_camera: KeyCode::Grave,
+		}
+	}
+}
+
+type CameraQuery<'a> = (
+	Entity,
+	&'a mut Camera,
+	Option<&'a mut FlyCamera>,
+	Option<&'a mut FlyCamera2d>,
+);
+
+fn set_camera_active(
+	mut camera: impl DerefMut<Target = Camera>,
+	fly_camera: &mut Option<impl DerefMut<Target = FlyCamera>>,
+	fly_camera_2d: &mut Option<impl DerefMut<Target = FlyCamera2d>>,
+	is_active: bool,
+) {
+	camera.is_active = is_active;
+	if let Some(fly_camera) = fly_camera {
+		fly_camera.enabled = is_active;
+	}
+	if let Some(fly_camera_2d) = fly_camera_2d {
+		fly_camera_2d.enabled = is_active;
+	}
+}
+
+// Runs every frame (rather than only at startup) so cameras that appear after the app starts,
+// such as ones spawned from a loaded glTF scene, are still swept into the single-active invariant.
+fn camera_active_init_system(
+	mut commands: Commands,
+	active_query: Query<Entity, With<FlyCameraActive>>,
+	mut new_cameras: Query<CameraQuery<'_>, Added<Camera>>,
+) {
+	let mut has_active = !active_query.is_empty();
+
+	for (entity, camera, mut fly_camera, mut fly_camera_2d) in new_cameras.iter_mut() {
+		let is_active = !has_active;
+		set_camera_active(camera, &mut fly_camera, &mut fly_camera_2d, is_active);
+		if is_active {
+			commands.entity(entity).insert(FlyCameraActive);
+			has_active = true;
+		}
+	}
+}
+
+fn camera_cycle_system(
+	keyboard_input: Res<Input<KeyCode>>,
+	cycle_config: Res<CameraCycleConfig>,
+	mut commands: Commands,
+	active_query: Query<Entity, With<FlyCameraActive>>,
+	mut cameras: Query<CameraQuery<'_>>,
+) {
+	if !keyboard_input.just_pressed(cycle_config.key_next_camera) {
+		return;
+	}
+
+	let mut entities: Vec<Entity> = cameras.iter().map(|(entity, _, _, _)| entity).collect();
+	if entities.len() < 2 {
+		return;
+	}
+	entities.sort();
+
+	let current_entity = active_query.iter().next();
+	let current_index = current_entity
+		.and_then(|entity| entities.iter().position(|&candidate| candidate == entity))
+		.unwrap_or(0);
+	let next_index = (current_index + 1) % entities.len();
+	let next_entity = entities[next_index];
+
+	if let Some(current_entity) = current_entity {
+		if let Ok((_, camera, mut fly_camera, mut fly_camera_2d)) = cameras.get_mut(current_entity) {
+			set_camera_active(camera, &mut fly_camera, &mut fly_camera_2d, false);
+		}
+		commands.entity(current_entity).remove::<FlyCameraActive>();
+	}
+
+	if let Ok((_, camera, mut fly_camera, mut fly_camera_2d)) = cameras.get_mut(next_entity) {
+		set_camera_active(camera, &mut fly_camera, &mut fly_camera_2d, true);
 	}
+	commands.entity(next_entity).insert(FlyCameraActive);
 }
 
 /**
@@ -324,19 +719,46 @@ impl Plugin for FlyCameraPlugin {
 	fn build(&self, app: &mut AppBuilder) {
 		app
 			// .add_system(camera_movement_system.system())
-			.add_system(camera_2d_movement_system.system())
+			.add_system(camera_2d_movement_system.system()
+				.label("camera_2d_movement_system")
+				.after("camera_active_init_system"))
 			.add_system(emit_camera_rotation_events.system()
-				.label("emit_camera_rotation_events"))
+				.label("emit_camera_rotation_events")
+				.after("camera_active_init_system"))
+			.add_system(emit_camera_key_look_events.system()
+				.label("emit_camera_key_look_events")
+				.after("camera_active_init_system"))
 			.add_system(consume_camera_rotation_events.system()
 				.label("consume_camera_rotation_events")
-				.after("emit_camera_rotation_events"))
+				.after("emit_camera_rotation_events")
+				.after("emit_camera_key_look_events"))
 			.add_system(emit_camera_movement_events.system()
-				.label("emit_camera_movement_events"))
+				.label("emit_camera_movement_events")
+				.after("camera_active_init_system"))
 			.add_system(consume_camera_events.system()
 				.label("consume_camera_movement_events")
-				.after("emit_camera_movement_events")
+				.after("emit_camera_movement_events"))
+			.add_system(emit_camera_scroll_events.system()
+				.label("emit_camera_scroll_events")
+				.after("camera_active_init_system"))
+			.add_system(consume_camera_scroll_events.system()
+				.label("consume_camera_scroll_events")
+				.after("emit_camera_scroll_events"))
+			.add_system(camera_mode_system.system()
+				.label("camera_mode_system")
+				.after("consume_camera_rotation_events")
+				.after("consume_camera_movement_events"))
+			// Runs before the emit/input systems so that, on a frame where several cameras are
+			// added at once (e.g. a glTF scene load), only one camera is ever marked active before
+			// any of them read input for that frame.
+			.add_system(camera_active_init_system.system()
+				.label("camera_active_init_system"))
+			.add_system(camera_cycle_system.system()
+				.label("camera_cycle_system")
+				.after("camera_active_init_system")
 		);
 
 		app.add_event::<camera_events::CameraEvent>();
+		app.init_resource::<CameraCycleConfig>();
 	}
 }
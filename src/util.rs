@@ -1,16 +1,88 @@
-use bevy::prelude::*;
-
-pub fn movement_axis(
-	input: &Res<Input<KeyCode>>,
-	plus: KeyCode,
-	minus: KeyCode,
-) -> f32 {
-	let mut axis = 0.0;
-	if input.pressed(plus) {
-		axis += 1.0;
-	}
-	if input.pressed(minus) {
-		axis -= 1.0;
-	}
-	axis
-}
+use bevy::prelude::*;
+
+pub fn movement_axis(
+	input: &Res<Input<KeyCode>>,
+	plus: KeyCode,
+	minus: KeyCode,
+) -> f32 {
+	let mut axis = 0.0;
+	if input.pressed(plus) {
+		axis += 1.0;
+	}
+	if input.pressed(minus) {
+		axis -= 1.0;
+	}
+	axis
+}
+
+/// The momentary speed multiplier from holding a sprint or slow key, shared by `FlyCamera` and `FlyCamera2d`.
+pub fn speed_multiplier(
+	input: &Res<Input<KeyCode>>,
+	key_sprint: KeyCode,
+	key_slow: KeyCode,
+	sprint_factor: f32,
+	slow_factor: f32,
+) -> f32 {
+	if input.pressed(key_sprint) {
+		sprint_factor
+	} else if input.pressed(key_slow) {
+		slow_factor
+	} else {
+		1.0
+	}
+}
+
+/// A vector type `FlyCamera`/`FlyCamera2d` velocity can be damped in, shared by [`integrate_velocity`].
+pub trait DampedVelocity:
+	Copy
+	+ std::ops::Add<Output = Self>
+	+ std::ops::Sub<Output = Self>
+	+ std::ops::Mul<f32, Output = Self>
+{
+	fn magnitude(self) -> f32;
+	fn normalized(self) -> Self;
+}
+
+impl DampedVelocity for Vec2 {
+	fn magnitude(self) -> f32 {
+		self.length()
+	}
+	fn normalized(self) -> Self {
+		self.normalize()
+	}
+}
+
+impl DampedVelocity for Vec3 {
+	fn magnitude(self) -> f32 {
+		self.length()
+	}
+	fn normalized(self) -> Self {
+		self.normalize()
+	}
+}
+
+/// Integrates `velocity` by `accel * dt`, clamps it to `max_speed`, then applies quadratic drag
+/// (clamped so it can't overshoot zero and flip the velocity's sign) followed by frame-rate-independent
+/// exponential damping (speed halves every `damping_half_life` seconds). Shared by `FlyCamera` and `FlyCamera2d`.
+pub fn integrate_velocity<V: DampedVelocity>(
+	velocity: V,
+	accel: V,
+	max_speed: f32,
+	drag_coeff: f32,
+	damping_half_life: f32,
+	dt: f32,
+) -> V {
+	let mut velocity = velocity + accel * dt;
+
+	if velocity.magnitude() > max_speed {
+		velocity = velocity.normalized() * max_speed;
+	}
+
+	if drag_coeff != 0.0 {
+		let drag_delta = (drag_coeff * velocity.magnitude() * dt).min(1.0);
+		velocity = velocity - velocity * drag_delta;
+	}
+
+	let decay = (-std::f32::consts::LN_2 * dt / damping_half_life).exp();
+	velocity * decay
+}